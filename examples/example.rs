@@ -24,7 +24,7 @@ impl Stage for PrintyStage {
 
 fn main() {
     let mut world = World;
-    let mut resources = Resources;
+    let mut resources = Resources::new();
     let mut schedule = Schedule::new();
     schedule.add(StructLabel(0), PrintyStage("I am struct label 0"));
     schedule.add(StructLabel(1), PrintyStage("I am struct label 1"));
@@ -1,10 +1,62 @@
+mod loop_stage;
 mod schedule;
+mod scheduling;
 mod stage;
 mod stage_label;
+mod state;
+mod system_label;
 
+pub use loop_stage::LoopStage;
 pub use schedule::Schedule;
+pub use scheduling::{
+    Executor, IntoSystemDescriptor, ParallelExecutor, SchedulerSystemContainer, SchedulingError,
+    SimpleExecutor, SingleThreadedExecutor, SystemDescriptor, UnorderedSchedulerSystem,
+};
 pub use stage::Stage;
 pub use stage_label::StageLabel;
+pub use state::{State, StateStage};
+pub use system_label::SystemLabel;
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
 
 pub struct World;
-pub struct Resources;
+
+/// Type-keyed bag of resources for the `Schedule`/`Stage` prototype; mirrors just enough of
+/// `bevy_ecs::Resources` (insert/get/get_mut by type) for stages like `StateStage` to stash and
+/// look up singletons such as `State<T>`.
+#[derive(Default)]
+pub struct Resources {
+    values: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl Resources {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn insert<T: 'static>(&mut self, resource: T) {
+        self.values.insert(TypeId::of::<T>(), Box::new(resource));
+    }
+
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut())
+    }
+
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.values
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast().ok())
+            .map(|value| *value)
+    }
+}
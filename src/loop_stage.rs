@@ -0,0 +1,40 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use crate::{Resources, Stage, World};
+
+/// A stage that wraps an inner boxed stage and keeps re-running it for as long as a predicate
+/// returns `true`, checked before every iteration (including the first). Composing this with
+/// `Schedule`'s `Stage` impl gives nested schedules, e.g. a fixed-timestep sub-schedule run a
+/// variable number of times per outer tick.
+pub struct LoopStage {
+    inner: Box<dyn Stage>,
+    should_keep_running: Box<dyn FnMut(&World, &Resources) -> bool>,
+}
+
+impl LoopStage {
+    pub fn new(
+        inner: impl Stage,
+        should_keep_running: impl FnMut(&World, &Resources) -> bool + 'static,
+    ) -> Self {
+        Self {
+            inner: Box::new(inner),
+            should_keep_running: Box::new(should_keep_running),
+        }
+    }
+}
+
+impl Stage for LoopStage {
+    fn run(&mut self, world: &mut World, resources: &mut Resources) {
+        while (self.should_keep_running)(world, resources) {
+            self.inner.run(world, resources);
+        }
+    }
+}
+
+impl Debug for LoopStage {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("LoopStage")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
@@ -5,9 +5,24 @@ use std::{
 
 use crate::{Resources, Stage, StageLabel, World};
 
+/// A boxed predicate evaluated against the world/resources before a stage runs; the stage is
+/// skipped entirely for this call to `Schedule::run` if any of its conditions returns `false`.
+pub type RunCondition = Box<dyn FnMut(&World, &Resources) -> bool>;
+
+struct StageEntry {
+    stage: Box<dyn Stage>,
+    conditions: Vec<RunCondition>,
+}
+
+impl Debug for StageEntry {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Debug::fmt(&self.stage, f)
+    }
+}
+
 #[derive(Default)]
 pub struct Schedule {
-    stages: Vec<Box<dyn Stage>>,
+    stages: Vec<StageEntry>,
     index_table: HashMap<Box<dyn StageLabel>, usize>,
 }
 
@@ -21,7 +36,13 @@ impl Schedule {
     }
 
     fn insert_stage(&mut self, stage_index: usize, stage: impl Stage) {
-        self.stages.insert(stage_index, Box::new(stage));
+        self.stages.insert(
+            stage_index,
+            StageEntry {
+                stage: Box::new(stage),
+                conditions: Vec::new(),
+            },
+        );
         for index in self
             .index_table
             .values_mut()
@@ -32,7 +53,7 @@ impl Schedule {
     }
 
     fn remove_stage<S: Stage>(&mut self, stage_index: usize) -> S {
-        let stage = self.stages.remove(stage_index);
+        let entry = self.stages.remove(stage_index);
         for index in self
             .index_table
             .values_mut()
@@ -40,7 +61,7 @@ impl Schedule {
         {
             *index -= 1;
         }
-        *stage.downcast::<S>().map_err(|_| ()).unwrap()
+        *entry.stage.downcast::<S>().map_err(|_| ()).unwrap()
     }
 
     fn insert_label(&mut self, stage_index: usize, label: impl StageLabel) {
@@ -54,7 +75,24 @@ impl Schedule {
 
     pub fn add(&mut self, label: impl StageLabel, stage: impl Stage) {
         self.insert_label(self.stages.len(), label);
-        self.stages.push(Box::new(stage));
+        self.stages.push(StageEntry {
+            stage: Box::new(stage),
+            conditions: Vec::new(),
+        });
+    }
+
+    /// Like `add`, but the stage only runs while `condition` returns `true`.
+    pub fn add_with_condition(
+        &mut self,
+        label: impl StageLabel,
+        stage: impl Stage,
+        condition: impl FnMut(&World, &Resources) -> bool + 'static,
+    ) {
+        self.insert_label(self.stages.len(), label);
+        self.stages.push(StageEntry {
+            stage: Box::new(stage),
+            conditions: vec![Box::new(condition)],
+        });
     }
 
     pub fn add_before(
@@ -88,16 +126,39 @@ impl Schedule {
         self.index_table
             .get(label as &dyn StageLabel)
             .cloned()
-            .and_then(move |index| self.stages[index].downcast_mut())
+            .and_then(move |index| self.stages[index].stage.downcast_mut())
+    }
+
+    /// Attaches an additional run condition to an already-inserted stage; the stage is skipped
+    /// whenever any of its conditions, old or new, returns `false`.
+    pub fn with_run_condition(
+        &mut self,
+        label: &impl StageLabel,
+        condition: impl FnMut(&World, &Resources) -> bool + 'static,
+    ) {
+        let index = self.stage_index(label).unwrap();
+        self.stages[index].conditions.push(Box::new(condition));
     }
 
     pub fn run(&mut self, world: &mut World, resources: &mut Resources) {
-        for stage in &mut self.stages {
-            stage.run(world, resources);
+        for entry in &mut self.stages {
+            let should_run = entry
+                .conditions
+                .iter_mut()
+                .all(|condition| condition(world, resources));
+            if should_run {
+                entry.stage.run(world, resources);
+            }
         }
     }
 }
 
+impl Stage for Schedule {
+    fn run(&mut self, world: &mut World, resources: &mut Resources) {
+        Schedule::run(self, world, resources)
+    }
+}
+
 impl Debug for Schedule {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         let mut index_table = self.index_table.iter().collect::<Vec<_>>();
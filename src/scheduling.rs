@@ -9,7 +9,9 @@ use bevy::{
 };
 use event_listener::Event;
 use parking_lot::Mutex;
-use std::{borrow::Cow, collections::HashSet, sync::Arc};
+use std::{borrow::Cow, collections::HashSet, fmt::Debug, sync::Arc};
+
+use crate::SystemLabel;
 
 pub struct SchedulerSystemContainer {
     /// Boxed `bevy_ecs` system.
@@ -23,66 +25,135 @@ pub struct SchedulerSystemContainer {
     pub deps_total: usize,
     /// Amount of unsatisfied dependencies, when it reaches 0 the system is queued to be started.
     pub deps_now: usize,
+    /// The label this system was inserted under, if any; used to resolve ambiguity ignore-lists.
+    pub label: Option<Box<dyn SystemLabel>>,
 }
 
-pub struct UnorderedSchedulerSystem {
-    /// Required for `System` implementation.
-    pub(crate) name: Cow<'static, str>,
-    /// Required for `System` implementation.
-    pub(crate) id: SystemId,
-    /// Required for `System` implementation.
-    pub(crate) resource_access: TypeAccess,
-    /// Required for `System` implementation.
-    pub(crate) archetype_access: ArchetypeAccess,
-    /// Used by systems to notify the scheduler that they have finished.
-    pub(crate) sender: Sender<SystemId>,
-    /// Used to receive finish notifications from systems.
-    pub(crate) receiver: Receiver<SystemId>,
-    /// Used to detect if the archetypes in the world have changed.
-    pub(crate) last_archetypes_generation: ArchetypesGeneration,
-    /// Systems the scheduler will be executing.
-    pub(crate) system_containers: HashMap<SystemId, SchedulerSystemContainer>,
-    /// Systems that should be started at next opportunity.
-    pub(crate) queued: Vec<SystemId>,
-    /// Systems that are currently running.
-    pub(crate) running: HashSet<SystemId>,
-    /// Scratch space to avoid reallocating a vector when updating dependency counters.
-    pub(crate) dependants_scratch: Vec<SystemId>,
+/// Execution policy for a set of systems sharing a dependency graph: decides how (and how
+/// concurrently) `UnorderedSchedulerSystem` drives its `system_containers` to completion.
+pub trait Executor: Debug {
+    /// Runs every system in `containers` exactly once, respecting `deps_total`/`dependants`.
+    fn run(
+        &mut self,
+        containers: &mut HashMap<SystemId, SchedulerSystemContainer>,
+        world: &mut World,
+        resources: &mut Resources,
+    );
 }
 
-impl System for UnorderedSchedulerSystem {
-    fn name(&self) -> Cow<'static, str> {
-        self.name.clone()
+/// Computes a valid run order for `containers` from their `deps_total`/`dependants`, via Kahn's
+/// algorithm. Used by the executors that don't need the full concurrency machinery.
+fn topological_order(containers: &HashMap<SystemId, SchedulerSystemContainer>) -> Vec<SystemId> {
+    let mut remaining: HashMap<SystemId, usize> = containers
+        .iter()
+        .map(|(&id, container)| (id, container.deps_total))
+        .collect();
+    let mut ready: Vec<SystemId> = remaining
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&id, _)| id)
+        .collect();
+    let mut order = Vec::with_capacity(containers.len());
+    while let Some(id) = ready.pop() {
+        order.push(id);
+        for &dependant in &containers[&id].dependants {
+            let count = remaining
+                .get_mut(&dependant)
+                .unwrap_or_else(|| unreachable!());
+            *count -= 1;
+            if *count == 0 {
+                ready.push(dependant);
+            }
+        }
     }
+    order
+}
 
-    fn id(&self) -> SystemId {
-        self.id
+/// Runs systems one at a time in topological order with no task pool, trading throughput for a
+/// deterministic run order and no thread requirement; fits wasm targets and debugging sessions.
+#[derive(Debug, Default)]
+pub struct SingleThreadedExecutor;
+
+impl Executor for SingleThreadedExecutor {
+    fn run(
+        &mut self,
+        containers: &mut HashMap<SystemId, SchedulerSystemContainer>,
+        world: &mut World,
+        resources: &mut Resources,
+    ) {
+        for id in topological_order(containers) {
+            let mut system = containers[&id].system.lock();
+            system.update_archetype_access(world);
+            system.run(world, resources);
+        }
     }
+}
 
-    fn update_archetype_access(&mut self, _: &World) {}
+/// Like `SingleThreadedExecutor`, but also flushes each system's thread-local command buffer
+/// before starting the next one, so every system sees a world that is fully up to date.
+#[derive(Debug, Default)]
+pub struct SimpleExecutor;
 
-    fn archetype_access(&self) -> &ArchetypeAccess {
-        &self.archetype_access
+impl Executor for SimpleExecutor {
+    fn run(
+        &mut self,
+        containers: &mut HashMap<SystemId, SchedulerSystemContainer>,
+        world: &mut World,
+        resources: &mut Resources,
+    ) {
+        for id in topological_order(containers) {
+            let mut system = containers[&id].system.lock();
+            system.update_archetype_access(world);
+            system.run(world, resources);
+            system.run_thread_local(world, resources);
+        }
     }
+}
 
-    fn resource_access(&self) -> &TypeAccess {
-        &self.resource_access
-    }
+/// Runs systems concurrently on the `ComputeTaskPool`, starting each as soon as its dependency
+/// counter reaches zero and it's compatible with everything currently running.
+pub struct ParallelExecutor {
+    /// Used by systems to notify the scheduler that they have finished.
+    sender: Sender<SystemId>,
+    /// Used to receive finish notifications from systems.
+    receiver: Receiver<SystemId>,
+    /// Used to detect if the archetypes in the world have changed.
+    last_archetypes_generation: ArchetypesGeneration,
+    /// Systems that should be started at next opportunity.
+    queued: Vec<SystemId>,
+    /// Systems that are currently running.
+    running: HashSet<SystemId>,
+    /// Scratch space to avoid reallocating a vector when updating dependency counters.
+    dependants_scratch: Vec<SystemId>,
+}
 
-    fn thread_local_execution(&self) -> ThreadLocalExecution {
-        ThreadLocalExecution::Immediate
+impl Default for ParallelExecutor {
+    fn default() -> Self {
+        let (sender, receiver) = async_channel::unbounded();
+        Self {
+            sender,
+            receiver,
+            last_archetypes_generation: ArchetypesGeneration::default(),
+            queued: Vec::new(),
+            running: HashSet::new(),
+            dependants_scratch: Vec::new(),
+        }
     }
+}
 
-    fn run(&mut self, _: &World, _: &Resources) {}
-
-    fn run_thread_local(&mut self, world: &mut World, resources: &mut Resources) {
-        self.run_systems(world, resources)
+impl Debug for ParallelExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ParallelExecutor").finish()
     }
 }
 
-impl UnorderedSchedulerSystem {
-    /// Runs all systems.
-    pub(crate) fn run_systems(&mut self, world: &mut World, resources: &mut Resources) {
+impl Executor for ParallelExecutor {
+    fn run(
+        &mut self,
+        containers: &mut HashMap<SystemId, SchedulerSystemContainer>,
+        world: &mut World,
+        resources: &mut Resources,
+    ) {
         debug_assert!(self.queued.is_empty());
         debug_assert!(self.running.is_empty());
         debug_assert!(self.dependants_scratch.is_empty());
@@ -90,19 +161,19 @@ impl UnorderedSchedulerSystem {
             .get_cloned::<ComputeTaskPool>()
             .unwrap()
             .scope(|scope| {
-                self.prepare(scope, world, resources);
+                self.prepare(containers, scope, world, resources);
                 // Spawn the scheduling task.
                 scope.spawn(async {
                     // All systems have been ran if there are no queued or running systems.
                     while !(self.queued.is_empty() && self.running.is_empty()) {
-                        self.start_all_runnable_queued_systems();
+                        self.start_all_runnable_queued_systems(containers);
                         // Wait until at least one system has finished.
                         let finished = self.receiver.recv().await.unwrap();
-                        self.process_finished_system(finished);
+                        self.process_finished_system(containers, finished);
                         while let Ok(finished) = self.receiver.try_recv() {
-                            self.process_finished_system(finished);
+                            self.process_finished_system(containers, finished);
                         }
-                        self.update_counters_and_queue_systems();
+                        self.update_counters_and_queue_systems(containers);
                     }
                 })
             });
@@ -110,17 +181,20 @@ impl UnorderedSchedulerSystem {
         debug_assert!(self.running.is_empty());
         debug_assert!(self.dependants_scratch.is_empty());
     }
+}
 
+impl ParallelExecutor {
     /// Resets dependency counters, updates archetype access if needed, and spawns system tasks.
     fn prepare<'scope>(
         &mut self,
+        containers: &mut HashMap<SystemId, SchedulerSystemContainer>,
         scope: &mut Scope<'scope, ()>,
         world: &'scope World,
         resources: &'scope Resources,
     ) {
         let sender = &self.sender;
         // Reset dependency counters and spawn system tasks.
-        let iterator = self.system_containers.iter_mut().map(|(&id, container)| {
+        let iterator = containers.iter_mut().map(|(&id, container)| {
             debug_assert!(container.deps_now == 0);
             container.deps_now = container.deps_total;
             let system = container.system.clone();
@@ -163,11 +237,14 @@ impl UnorderedSchedulerSystem {
 
     /// Signals all queued systems with satisfied dependencies to start if they can, and moves
     /// them from `queued` to `running`.
-    fn start_all_runnable_queued_systems(&mut self) {
+    fn start_all_runnable_queued_systems(
+        &mut self,
+        containers: &HashMap<SystemId, SchedulerSystemContainer>,
+    ) {
         for &id in &self.queued {
-            if self.can_start_now(id) {
+            if self.can_start_now(containers, id) {
                 self.running.insert(id);
-                self.system_containers
+                containers
                     .get(&id)
                     .unwrap_or_else(|| unreachable!())
                     .notifier
@@ -180,17 +257,19 @@ impl UnorderedSchedulerSystem {
     }
 
     /// Determines if the system with given ID can run concurrently with already running systems.
-    fn can_start_now(&self, id: SystemId) -> bool {
+    fn can_start_now(
+        &self,
+        containers: &HashMap<SystemId, SchedulerSystemContainer>,
+        id: SystemId,
+    ) -> bool {
         // TODO I hate this.
-        let system = self
-            .system_containers
+        let system = containers
             .get(&id)
             .unwrap_or_else(|| unreachable!())
             .system
             .lock();
         for id in &self.running {
-            let other = self
-                .system_containers
+            let other = containers
                 .get(id)
                 .unwrap_or_else(|| unreachable!())
                 .system
@@ -212,24 +291,25 @@ impl UnorderedSchedulerSystem {
     }
 
     /// Removes system from `running` and stores it's dependants in `dependants_scratch`.
-    fn process_finished_system(&mut self, id: SystemId) {
+    fn process_finished_system(
+        &mut self,
+        containers: &HashMap<SystemId, SchedulerSystemContainer>,
+        id: SystemId,
+    ) {
         self.running.remove(&id);
-        let container = self
-            .system_containers
-            .get(&id)
-            .unwrap_or_else(|| unreachable!());
+        let container = containers.get(&id).unwrap_or_else(|| unreachable!());
         self.dependants_scratch
             .extend(container.dependants.iter().cloned());
     }
 
     /// Decrements dependency counters for systems in `dependants_scratch` and moves the ones with
     /// satisfied dependencies to `queued`.
-    fn update_counters_and_queue_systems(&mut self) {
+    fn update_counters_and_queue_systems(
+        &mut self,
+        containers: &mut HashMap<SystemId, SchedulerSystemContainer>,
+    ) {
         for id in self.dependants_scratch.drain(..) {
-            let container = self
-                .system_containers
-                .get_mut(&id)
-                .unwrap_or_else(|| unreachable!());
+            let container = containers.get_mut(&id).unwrap_or_else(|| unreachable!());
             container.deps_now -= 1;
             if container.deps_now == 0 {
                 self.queued.push(id);
@@ -237,3 +317,404 @@ impl UnorderedSchedulerSystem {
         }
     }
 }
+
+pub struct UnorderedSchedulerSystem {
+    /// Required for `System` implementation.
+    pub(crate) name: Cow<'static, str>,
+    /// Required for `System` implementation.
+    pub(crate) id: SystemId,
+    /// Required for `System` implementation.
+    pub(crate) resource_access: TypeAccess,
+    /// Required for `System` implementation.
+    pub(crate) archetype_access: ArchetypeAccess,
+    /// Systems the scheduler will be executing.
+    pub(crate) system_containers: HashMap<SystemId, SchedulerSystemContainer>,
+    /// The execution policy used to drive `system_containers` to completion.
+    pub(crate) executor: Box<dyn Executor>,
+}
+
+impl System for UnorderedSchedulerSystem {
+    fn name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+
+    fn id(&self) -> SystemId {
+        self.id
+    }
+
+    fn update_archetype_access(&mut self, _: &World) {}
+
+    fn archetype_access(&self) -> &ArchetypeAccess {
+        &self.archetype_access
+    }
+
+    fn resource_access(&self) -> &TypeAccess {
+        &self.resource_access
+    }
+
+    fn thread_local_execution(&self) -> ThreadLocalExecution {
+        ThreadLocalExecution::Immediate
+    }
+
+    fn run(&mut self, _: &World, _: &Resources) {}
+
+    fn run_thread_local(&mut self, world: &mut World, resources: &mut Resources) {
+        self.executor
+            .run(&mut self.system_containers, world, resources)
+    }
+}
+
+/// Error produced while turning a set of [`SystemDescriptor`]s into an [`UnorderedSchedulerSystem`].
+#[derive(Debug)]
+pub enum SchedulingError {
+    /// The `.before()`/`.after()` constraints formed a cycle, so no run order satisfies them.
+    CyclicDependency,
+    /// A `.before()`/`.after()` constraint named a label no system declared via `.label()`.
+    UnresolvedLabel,
+}
+
+/// A boxed system together with the ordering constraints declared for it via
+/// `.label()`/`.before()`/`.after()`. Built up by [`IntoSystemDescriptor`] and consumed by
+/// [`UnorderedSchedulerSystem::from_descriptors`].
+pub struct SystemDescriptor {
+    pub(crate) system: Box<dyn System>,
+    pub(crate) label: Option<Box<dyn SystemLabel>>,
+    pub(crate) before: Vec<Box<dyn SystemLabel>>,
+    pub(crate) after: Vec<Box<dyn SystemLabel>>,
+}
+
+impl SystemDescriptor {
+    fn new(system: Box<dyn System>) -> Self {
+        Self {
+            system,
+            label: None,
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+}
+
+/// Lets a system (or an already-started descriptor) declare ordering constraints that the
+/// scheduler turns into the dependency graph `UnorderedSchedulerSystem` runs on.
+pub trait IntoSystemDescriptor {
+    /// Gives this system a label other systems can refer to in `.before()`/`.after()`.
+    fn label(self, label: impl SystemLabel) -> SystemDescriptor;
+
+    /// Declares that this system must run before the system labelled `label`.
+    fn before(self, label: impl SystemLabel) -> SystemDescriptor;
+
+    /// Declares that this system must run after the system labelled `label`.
+    fn after(self, label: impl SystemLabel) -> SystemDescriptor;
+}
+
+impl<T> IntoSystemDescriptor for T
+where
+    T: System,
+{
+    fn label(self, label: impl SystemLabel) -> SystemDescriptor {
+        SystemDescriptor::new(Box::new(self)).label(label)
+    }
+
+    fn before(self, label: impl SystemLabel) -> SystemDescriptor {
+        SystemDescriptor::new(Box::new(self)).before(label)
+    }
+
+    fn after(self, label: impl SystemLabel) -> SystemDescriptor {
+        SystemDescriptor::new(Box::new(self)).after(label)
+    }
+}
+
+impl IntoSystemDescriptor for SystemDescriptor {
+    fn label(mut self, label: impl SystemLabel) -> SystemDescriptor {
+        self.label = Some(Box::new(label));
+        self
+    }
+
+    fn before(mut self, label: impl SystemLabel) -> SystemDescriptor {
+        self.before.push(Box::new(label));
+        self
+    }
+
+    fn after(mut self, label: impl SystemLabel) -> SystemDescriptor {
+        self.after.push(Box::new(label));
+        self
+    }
+}
+
+impl UnorderedSchedulerSystem {
+    /// Builds a scheduler system out of descriptors and an `executor`, resolving their
+    /// `.label()`/`.before()`/`.after()` constraints into the dependency graph the executor
+    /// runs systems against.
+    ///
+    /// Internally this builds a directed graph where an edge `a -> b` means "`a` must run
+    /// before `b`" and runs Kahn's algorithm over it purely to reject cycles early; the
+    /// resulting in-degrees and successor lists become `deps_total`/`dependants` on each
+    /// container, so the counter-based executor needs no further changes.
+    ///
+    /// A `.before()`/`.after()` naming a label no system declared via `.label()` is rejected
+    /// with `SchedulingError::UnresolvedLabel` rather than silently producing no edge, since a
+    /// dropped ordering constraint would otherwise look like a valid, deterministic schedule.
+    pub fn from_descriptors(
+        descriptors: Vec<SystemDescriptor>,
+        executor: Box<dyn Executor>,
+    ) -> Result<Self, SchedulingError> {
+        let labelled_ids: HashMap<Box<dyn SystemLabel>, SystemId> = descriptors
+            .iter()
+            .filter_map(|descriptor| {
+                descriptor
+                    .label
+                    .as_ref()
+                    .map(|label| (label.clone(), descriptor.system.id()))
+            })
+            .collect();
+
+        let mut dependants: HashMap<SystemId, Vec<SystemId>> = descriptors
+            .iter()
+            .map(|descriptor| (descriptor.system.id(), Vec::new()))
+            .collect();
+        let mut in_degree: HashMap<SystemId, usize> = descriptors
+            .iter()
+            .map(|descriptor| (descriptor.system.id(), 0))
+            .collect();
+
+        for descriptor in &descriptors {
+            let id = descriptor.system.id();
+            for before in &descriptor.before {
+                let successor = *labelled_ids
+                    .get(before)
+                    .ok_or(SchedulingError::UnresolvedLabel)?;
+                dependants.get_mut(&id).unwrap().push(successor);
+                *in_degree.get_mut(&successor).unwrap() += 1;
+            }
+            for after in &descriptor.after {
+                let predecessor = *labelled_ids
+                    .get(after)
+                    .ok_or(SchedulingError::UnresolvedLabel)?;
+                dependants.get_mut(&predecessor).unwrap().push(id);
+                *in_degree.get_mut(&id).unwrap() += 1;
+            }
+        }
+
+        // Kahn's algorithm: repeatedly pop nodes with in-degree 0, decrementing their
+        // successors', and error if some nodes never reach zero (a cycle).
+        let mut remaining = in_degree.clone();
+        let mut ready: Vec<SystemId> = remaining
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&id, _)| id)
+            .collect();
+        let mut visited = 0;
+        while let Some(id) = ready.pop() {
+            visited += 1;
+            for &successor in &dependants[&id] {
+                let count = remaining.get_mut(&successor).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(successor);
+                }
+            }
+        }
+        if visited != descriptors.len() {
+            return Err(SchedulingError::CyclicDependency);
+        }
+
+        let mut resource_access = TypeAccess::default();
+        let mut archetype_access = ArchetypeAccess::default();
+        let mut system_containers = HashMap::default();
+        for descriptor in descriptors {
+            let id = descriptor.system.id();
+            resource_access.union(descriptor.system.resource_access());
+            archetype_access.union(descriptor.system.archetype_access());
+            system_containers.insert(
+                id,
+                SchedulerSystemContainer {
+                    system: Arc::new(Mutex::new(descriptor.system)),
+                    notifier: Event::new(),
+                    dependants: dependants.remove(&id).unwrap_or_default(),
+                    deps_total: in_degree.remove(&id).unwrap_or(0),
+                    deps_now: 0,
+                    label: descriptor.label,
+                },
+            );
+        }
+
+        Ok(Self {
+            name: Cow::Borrowed("unordered_scheduler_system"),
+            id: SystemId::new(),
+            resource_access,
+            archetype_access,
+            system_containers,
+            executor,
+        })
+    }
+
+    /// Finds pairs of systems whose `resource_access`/`archetype_access` conflict but that have
+    /// no ordering edge between them, directly or transitively, meaning their relative run
+    /// order is nondeterministic. Pairs whose labels both appear (in either order) in `ignore`
+    /// are not reported.
+    pub fn detect_ambiguities(
+        &self,
+        ignore: &[(Box<dyn SystemLabel>, Box<dyn SystemLabel>)],
+    ) -> Vec<(SystemId, SystemId)> {
+        let reachable = self.transitive_closure();
+        let ids: Vec<SystemId> = self.system_containers.keys().copied().collect();
+        let mut ambiguities = Vec::new();
+        for (index, &a) in ids.iter().enumerate() {
+            for &b in &ids[index + 1..] {
+                if reachable[&a].contains(&b) || reachable[&b].contains(&a) {
+                    continue;
+                }
+                if self.is_ignored(a, b, ignore) {
+                    continue;
+                }
+                let system_a = self.system_containers[&a].system.lock();
+                let system_b = self.system_containers[&b].system.lock();
+                let ambiguous = !system_a
+                    .resource_access()
+                    .is_compatible(system_b.resource_access())
+                    || !system_a
+                        .archetype_access()
+                        .is_compatible(system_b.archetype_access());
+                if ambiguous {
+                    ambiguities.push((a, b));
+                }
+            }
+        }
+        ambiguities
+    }
+
+    /// For every system, computes the set of systems reachable from it via dependency edges,
+    /// i.e. the ones it's guaranteed to run before. A DFS over the same DAG used for
+    /// scheduling, precomputed so `detect_ambiguities` can test pairwise reachability in O(1).
+    fn transitive_closure(&self) -> HashMap<SystemId, HashSet<SystemId>> {
+        let mut closure = HashMap::default();
+        for &id in self.system_containers.keys() {
+            let mut visited = HashSet::new();
+            let mut stack = self.system_containers[&id].dependants.clone();
+            while let Some(next) = stack.pop() {
+                if visited.insert(next) {
+                    stack.extend(self.system_containers[&next].dependants.iter().copied());
+                }
+            }
+            closure.insert(id, visited);
+        }
+        closure
+    }
+
+    /// Whether `a` and `b` were declared under a labelled pair present in `ignore`.
+    fn is_ignored(
+        &self,
+        a: SystemId,
+        b: SystemId,
+        ignore: &[(Box<dyn SystemLabel>, Box<dyn SystemLabel>)],
+    ) -> bool {
+        let (label_a, label_b) = match (
+            self.system_containers[&a].label.as_deref(),
+            self.system_containers[&b].label.as_deref(),
+        ) {
+            (Some(label_a), Some(label_b)) => (label_a, label_b),
+            _ => return false,
+        };
+        ignore.iter().any(|(x, y)| {
+            (x.as_ref() == label_a && y.as_ref() == label_b)
+                || (x.as_ref() == label_b && y.as_ref() == label_a)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct NoopSystem {
+        name: Cow<'static, str>,
+        id: SystemId,
+        resource_access: TypeAccess,
+        archetype_access: ArchetypeAccess,
+    }
+
+    impl NoopSystem {
+        fn new(name: &'static str) -> Self {
+            Self {
+                name: Cow::Borrowed(name),
+                id: SystemId::new(),
+                resource_access: TypeAccess::default(),
+                archetype_access: ArchetypeAccess::default(),
+            }
+        }
+    }
+
+    impl System for NoopSystem {
+        fn name(&self) -> Cow<'static, str> {
+            self.name.clone()
+        }
+
+        fn id(&self) -> SystemId {
+            self.id
+        }
+
+        fn update_archetype_access(&mut self, _: &World) {}
+
+        fn archetype_access(&self) -> &ArchetypeAccess {
+            &self.archetype_access
+        }
+
+        fn resource_access(&self) -> &TypeAccess {
+            &self.resource_access
+        }
+
+        fn thread_local_execution(&self) -> ThreadLocalExecution {
+            ThreadLocalExecution::Immediate
+        }
+
+        fn run(&mut self, _: &World, _: &Resources) {}
+
+        fn run_thread_local(&mut self, _: &mut World, _: &mut Resources) {}
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct First;
+
+    impl SystemLabel for First {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Second;
+
+    impl SystemLabel for Second {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Missing;
+
+    impl SystemLabel for Missing {}
+
+    #[test]
+    fn from_descriptors_builds_deps_total_and_dependants() {
+        let first = NoopSystem::new("first").label(First);
+        let first_id = first.system.id();
+        let second = NoopSystem::new("second").label(Second).after(First);
+        let second_id = second.system.id();
+
+        let scheduler_system = UnorderedSchedulerSystem::from_descriptors(
+            vec![first, second],
+            Box::new(SimpleExecutor),
+        )
+        .unwrap();
+
+        let first_container = &scheduler_system.system_containers[&first_id];
+        assert_eq!(first_container.deps_total, 0);
+        assert_eq!(first_container.dependants, vec![second_id]);
+
+        let second_container = &scheduler_system.system_containers[&second_id];
+        assert_eq!(second_container.deps_total, 1);
+        assert!(second_container.dependants.is_empty());
+    }
+
+    #[test]
+    fn from_descriptors_rejects_unresolved_label() {
+        let descriptor = NoopSystem::new("lonely").after(Missing);
+        let result =
+            UnorderedSchedulerSystem::from_descriptors(vec![descriptor], Box::new(SimpleExecutor));
+        assert!(matches!(result, Err(SchedulingError::UnresolvedLabel)));
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    hash::Hash,
+};
+
+use crate::{Resources, Stage, World};
+
+/// Resource tracking which variant of `T` is currently active, plus a FIFO queue of requested
+/// transitions. A system can call `set_next` to request a change that `StateStage::run` applies
+/// at the next schedule boundary, rather than tearing down the current state mid-frame.
+pub struct State<T> {
+    current: T,
+    queue: Vec<T>,
+}
+
+impl<T: Clone + Eq + Hash + 'static> State<T> {
+    pub fn new(initial: T) -> Self {
+        Self {
+            current: initial,
+            queue: Vec::new(),
+        }
+    }
+
+    /// The state that's active right now.
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Queues `next` behind any transitions already queued; the driving `StateStage` applies
+    /// one queued transition each time it runs.
+    pub fn set_next(&mut self, next: T) {
+        self.queue.push(next);
+    }
+
+    /// Pops the next queued transition, if any.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.queue.is_empty() {
+            None
+        } else {
+            Some(self.queue.remove(0))
+        }
+    }
+}
+
+/// A stage driven by a `State<T>` resource: on each run, it pops a queued transition and runs
+/// the old state's `on_exit` stages followed by the new state's `on_enter` stages, then always
+/// runs the now-current state's `on_update` stages, transition or not.
+pub struct StateStage<T> {
+    on_enter: HashMap<T, Vec<Box<dyn Stage>>>,
+    on_exit: HashMap<T, Vec<Box<dyn Stage>>>,
+    on_update: HashMap<T, Vec<Box<dyn Stage>>>,
+}
+
+impl<T: Clone + Eq + Hash + 'static> Default for StateStage<T> {
+    fn default() -> Self {
+        Self {
+            on_enter: HashMap::new(),
+            on_exit: HashMap::new(),
+            on_update: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash + 'static> StateStage<T> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers `stage` to run once, in insertion order, when transitioning into `state`.
+    pub fn on_enter(&mut self, state: T, stage: impl Stage) {
+        self.on_enter
+            .entry(state)
+            .or_default()
+            .push(Box::new(stage));
+    }
+
+    /// Registers `stage` to run once, in insertion order, when transitioning out of `state`.
+    pub fn on_exit(&mut self, state: T, stage: impl Stage) {
+        self.on_exit.entry(state).or_default().push(Box::new(stage));
+    }
+
+    /// Registers `stage` to run every tick that `state` is current, including the tick that
+    /// transitions into it (after its `on_enter` stages).
+    pub fn on_update(&mut self, state: T, stage: impl Stage) {
+        self.on_update
+            .entry(state)
+            .or_default()
+            .push(Box::new(stage));
+    }
+
+    fn run_bucket(
+        bucket: &mut HashMap<T, Vec<Box<dyn Stage>>>,
+        state: &T,
+        world: &mut World,
+        resources: &mut Resources,
+    ) {
+        if let Some(stages) = bucket.get_mut(state) {
+            for stage in stages {
+                stage.run(world, resources);
+            }
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash + 'static> Stage for StateStage<T> {
+    fn run(&mut self, world: &mut World, resources: &mut Resources) {
+        let next = match resources.get_mut::<State<T>>() {
+            Some(state) => state.pop(),
+            None => return,
+        };
+        if let Some(next) = next {
+            let previous = resources.get::<State<T>>().unwrap().current().clone();
+            Self::run_bucket(&mut self.on_exit, &previous, world, resources);
+            resources.get_mut::<State<T>>().unwrap().current = next.clone();
+            Self::run_bucket(&mut self.on_enter, &next, world, resources);
+        }
+        let current = resources.get::<State<T>>().unwrap().current().clone();
+        Self::run_bucket(&mut self.on_update, &current, world, resources);
+    }
+}
+
+impl<T: 'static> Debug for StateStage<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("StateStage")
+            .field("on_enter", &self.on_enter.len())
+            .field("on_exit", &self.on_exit.len())
+            .field("on_update", &self.on_update.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Debug)]
+    struct RecordingStage {
+        log: Rc<RefCell<Vec<&'static str>>>,
+        message: &'static str,
+    }
+
+    impl Stage for RecordingStage {
+        fn run(&mut self, _: &mut World, _: &mut Resources) {
+            self.log.borrow_mut().push(self.message);
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum Phase {
+        A,
+        B,
+    }
+
+    fn recording_stage(
+        log: &Rc<RefCell<Vec<&'static str>>>,
+        message: &'static str,
+    ) -> RecordingStage {
+        RecordingStage {
+            log: Rc::clone(log),
+            message,
+        }
+    }
+
+    #[test]
+    fn transition_runs_exit_then_enter_then_update() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut stage = StateStage::new();
+        stage.on_exit(Phase::A, recording_stage(&log, "exit a"));
+        stage.on_enter(Phase::B, recording_stage(&log, "enter b"));
+        stage.on_update(Phase::B, recording_stage(&log, "update b"));
+
+        let mut world = World;
+        let mut resources = Resources::new();
+        resources.insert(State::new(Phase::A));
+        resources
+            .get_mut::<State<Phase>>()
+            .unwrap()
+            .set_next(Phase::B);
+
+        stage.run(&mut world, &mut resources);
+
+        assert_eq!(*log.borrow(), vec!["exit a", "enter b", "update b"]);
+        assert_eq!(
+            resources.get::<State<Phase>>().unwrap().current(),
+            &Phase::B
+        );
+    }
+
+    #[test]
+    fn no_transition_only_runs_update() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut stage = StateStage::new();
+        stage.on_exit(Phase::A, recording_stage(&log, "exit a"));
+        stage.on_enter(Phase::A, recording_stage(&log, "enter a"));
+        stage.on_update(Phase::A, recording_stage(&log, "update a"));
+
+        let mut world = World;
+        let mut resources = Resources::new();
+        resources.insert(State::new(Phase::A));
+
+        stage.run(&mut world, &mut resources);
+
+        assert_eq!(*log.borrow(), vec!["update a"]);
+    }
+}